@@ -24,9 +24,111 @@ pub mod types {
     }
 
     win_wrapper!(ErrorCode, DWORD);
-    win_wrapper!(WinClassAtom, ATOM);
+    impl ErrorCode {
+        /// Looks up this code's system message text via FormatMessageA, trimming the trailing
+        /// CRLF that FormatMessage appends
+        fn system_message(&self) -> String {
+            let mut buffer: LPSTR = 0 as LPSTR;
+
+            let flags = FORMAT_MESSAGE_ALLOCATE_BUFFER | FORMAT_MESSAGE_FROM_SYSTEM | FORMAT_MESSAGE_IGNORE_INSERTS;
+            let lang_id = make_lang_id(LANG_NEUTRAL, SUBLANG_DEFAULT);
+
+            let len = unsafe {
+                FormatMessageA(flags, 0 as LPVOID, self.get(), lang_id, &mut buffer, 0, 0 as LPVOID)
+            };
+
+            if len == 0 || buffer.is_null() {
+                return format!("Unknown error ({})", self.get());
+            }
+
+            let mut message = unsafe {
+                let bytes = std::slice::from_raw_parts(buffer, len as usize);
+                String::from_utf8_lossy(bytes).into_owned()
+            };
+
+            unsafe {
+                LocalFree(buffer as HLOCAL);
+            }
+
+            while message.ends_with('\r') || message.ends_with('\n') {
+                message.pop();
+            }
+
+            message
+        }
+    }
+    impl std::fmt::Display for ErrorCode {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.system_message())
+        }
+    }
+    impl std::fmt::Debug for ErrorCode {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_tuple("ErrorCode").field(&self.get()).finish()
+        }
+    }
+    impl std::error::Error for ErrorCode {}
+
+    /// Wraps the [ATOM] returned by [crate::register_class]/[crate::register_class_w].
+    /// `UnregisterClassA` is called automatically when this is dropped; since that requires the
+    /// class name/atom and the [HINSTANCE] it was registered with, this wrapper (unlike the others)
+    /// carries both rather than just the raw handle.
+    pub struct WinClassAtom {
+        atom: ATOM,
+        h_instance: HINSTANCE
+    }
+    impl WinClassAtom {
+        pub fn new(atom: ATOM, h_instance: HINSTANCE) -> Self {
+            WinClassAtom { atom, h_instance }
+        }
+        pub fn get(&self) -> ATOM {
+            self.atom
+        }
+        /// Unregisters the class now rather than waiting for `Drop`. Calling this more than once is a no-op.
+        pub fn unregister(&mut self) {
+            if self.atom != 0 {
+                unsafe {
+                    UnregisterClassA(self.atom as usize as LPCSTR, self.h_instance);
+                }
+                self.atom = 0;
+            }
+        }
+    }
+    impl Drop for WinClassAtom {
+        fn drop(&mut self) {
+            self.unregister();
+        }
+    }
+
     win_wrapper!(HInstance, HINSTANCE);
-    win_wrapper!(HWindow, HWND);
+
+    /// Wraps the [HWND] returned by [crate::create_window]/[crate::create_window_w]/
+    /// [crate::create_window_with_handler]. `DestroyWindow` is called automatically when this is
+    /// dropped, so windows get a deterministic lifetime instead of leaking for the process' lifetime.
+    pub struct HWindow(HWND);
+    impl HWindow {
+        pub fn new(val: HWND) -> Self {
+            HWindow(val)
+        }
+        pub fn get(&self) -> HWND {
+            self.0
+        }
+        /// Destroys the window now rather than waiting for `Drop`. Calling this more than once is a no-op.
+        pub fn destroy(&mut self) {
+            if !self.0.is_null() {
+                unsafe {
+                    DestroyWindow(self.0);
+                }
+                self.0 = 0 as HWND;
+            }
+        }
+    }
+    impl Drop for HWindow {
+        fn drop(&mut self) {
+            self.destroy();
+        }
+    }
+
     win_wrapper!(WinProc, WNDPROC);
     win_wrapper!(HIcon, HICON);
     win_wrapper!(HCursor, HCURSOR);
@@ -61,6 +163,63 @@ pub mod types {
             }
         }
     }
+    /// Safe wrapper around the raw [MSG] structure filled in by [crate::run_message_loop] and [crate::peek_message]
+    pub struct Message(MSG);
+    impl Message {
+        pub(crate) fn new(msg: MSG) -> Self {
+            Message(msg)
+        }
+        /// The window the message is destined for
+        pub fn hwnd(&self) -> HWND {
+            self.0.hwnd
+        }
+        /// The message identifier (one of the `WM_*` constants)
+        pub fn message(&self) -> UINT {
+            self.0.message
+        }
+        /// Additional message-specific information
+        pub fn w_param(&self) -> WPARAM {
+            self.0.wParam
+        }
+        /// Additional message-specific information
+        pub fn l_param(&self) -> LPARAM {
+            self.0.lParam
+        }
+    }
+
+    /// Null-terminated UTF-16 buffer, encoded from a `&str`, for use with the wide (`W`) Win32
+    /// APIs, analogous to [LPCString] for the ANSI (`A`) APIs.
+    pub struct WideString(Option<Vec<u16>>);
+    impl WideString {
+        /// Creates a null `WideString`
+        pub fn null() -> Self {
+            Self(None)
+        }
+        /// Creates a new `WideString` structure
+        pub fn new(val: &str) -> Self {
+            let encoded: Vec<u16> = val.encode_utf16().chain(Some(0)).collect();
+
+            WideString(Some(encoded))
+        }
+        /// Returns a const pointer to the first UTF-16 code unit of the internal buffer
+        ///
+        /// THIS CAN RETURN A NULL POINTER!
+        pub fn as_cwstr(&self) -> *const u16 {
+            match &self.0 {
+                Some(v) => v.as_ptr(),
+                None => 0 as *const u16
+            }
+        }
+    }
+
+    /// Trait for types that want to handle the messages sent to a window created via
+    /// [crate::create_window_with_handler], as an alternative to writing a raw WndProc by hand.
+    ///
+    /// Returning `None` lets the message fall through to `DefWindowProcA`.
+    pub trait WindowHandler {
+        fn handle(&mut self, msg: UINT, w_param: WPARAM, l_param: LPARAM) -> Option<LRESULT>;
+    }
+
     /// Simple wrapper for [WNDCLASSEXA]
     pub struct WinClass {
         pub style: UINT,
@@ -102,12 +261,227 @@ pub mod types {
             ret
         }
     }
+
+    /// Simple wrapper for [WNDCLASSEXW], the wide counterpart of [WinClass]
+    pub struct WinClassW {
+        pub style: UINT,
+        pub win_proc: WinProc,
+        pub cls_extra: i32,
+        pub win_extra: i32,
+        pub h_instance: HInstance,
+        pub h_icon: HIcon,
+        pub h_cursor: HCursor,
+        pub h_br_background: HBrush,
+        pub menu_name: WideString,
+        pub class_name: WideString,
+        pub h_icon_small: HIcon
+    }
+    impl Default for WinClassW {
+        fn default() -> WinClassW {
+            WinClassW {
+                style: 0,
+                win_proc: WinProc::new(None),
+                cls_extra: 0,
+                win_extra: 0,
+                h_instance: HInstance(0 as HANDLE),
+                h_icon: HIcon(0 as HICON),
+                h_cursor: HCursor(0 as HCURSOR),
+                h_br_background: HBrush(0 as HBRUSH),
+                menu_name: WideString::null(),
+                class_name: WideString::null(),
+                h_icon_small: HIcon(0 as HICON)
+            }
+        }
+    }
+    impl WinClassW {
+        /// Converts a [WinClassW] struct into the [WNDCLASSEXW] struct used internally by Win32
+        pub fn convert(&self) -> WNDCLASSEXW {
+            WNDCLASSEXW::new(&self)
+        }
+    }
+
+    /// Typed set of window style bits (the `WS_*` family), combinable with `|` instead of juggling a raw `u32`
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct WindowStyle(u32);
+    impl WindowStyle {
+        pub const NONE: WindowStyle = WindowStyle(0);
+        pub const BORDER: WindowStyle = WindowStyle(WS_BORDER);
+        pub const CAPTION: WindowStyle = WindowStyle(WS_CAPTION);
+        pub const CHILD: WindowStyle = WindowStyle(WS_CHILD);
+        pub const CLIP_CHILDREN: WindowStyle = WindowStyle(WS_CLIPCHILDREN);
+        pub const CLIP_SIBLINGS: WindowStyle = WindowStyle(WS_CLIPSIBLINGS);
+        pub const DISABLED: WindowStyle = WindowStyle(WS_DISABLED);
+        pub const DLG_FRAME: WindowStyle = WindowStyle(WS_DLGFRAME);
+        pub const GROUP: WindowStyle = WindowStyle(WS_GROUP);
+        pub const HSCROLL: WindowStyle = WindowStyle(WS_HSCROLL);
+        pub const MAXIMIZE: WindowStyle = WindowStyle(WS_MAXIMIZE);
+        pub const MAXIMIZE_BOX: WindowStyle = WindowStyle(WS_MAXIMIZEBOX);
+        pub const MINIMIZE: WindowStyle = WindowStyle(WS_MINIMIZE);
+        pub const MINIMIZE_BOX: WindowStyle = WindowStyle(WS_MINIMIZEBOX);
+        pub const OVERLAPPED: WindowStyle = WindowStyle(WS_OVERLAPPED);
+        pub const OVERLAPPED_WINDOW: WindowStyle = WindowStyle(WS_OVERLAPPEDWINDOW);
+        pub const POPUP: WindowStyle = WindowStyle(WS_POPUP);
+        pub const POPUP_WINDOW: WindowStyle = WindowStyle(WS_POPUPWINDOW);
+        pub const SIZE_BOX: WindowStyle = WindowStyle(WS_SIZEBOX);
+        pub const SYS_MENU: WindowStyle = WindowStyle(WS_SYSMENU);
+        pub const TAB_STOP: WindowStyle = WindowStyle(WS_TABSTOP);
+        pub const VISIBLE: WindowStyle = WindowStyle(WS_VISIBLE);
+        pub const VSCROLL: WindowStyle = WindowStyle(WS_VSCROLL);
+
+        /// Returns the raw `u32` style bits, as consumed by `CreateWindowExA`/`CreateWindowExW`
+        pub fn bits(&self) -> u32 {
+            self.0
+        }
+    }
+    impl std::ops::BitOr for WindowStyle {
+        type Output = WindowStyle;
+        fn bitor(self, rhs: WindowStyle) -> WindowStyle {
+            WindowStyle(self.0 | rhs.0)
+        }
+    }
+
+    /// Builder for a `CreateWindowExA` call, replacing [crate::create_window]'s hardcoded style,
+    /// position and size with chained setters that default to `CW_USEDEFAULT` for position/size and
+    /// [WindowStyle::NONE] for style. It takes ANSI [LPCString] names only; there is no wide (`W`) builder.
+    pub struct WindowBuilder {
+        class_name: LPCString,
+        name: Option<LPCString>,
+        style: WindowStyle,
+        ex_style: Option<DWORD>,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        parent: HWND,
+        menu: HMENU,
+        h_instance: HINSTANCE
+    }
+    impl WindowBuilder {
+        /// Creates a builder for a window of the given registered class
+        pub fn new(class_name: LPCString) -> Self {
+            WindowBuilder {
+                class_name,
+                name: None,
+                style: WindowStyle::NONE,
+                ex_style: None,
+                x: CW_USEDEFAULT,
+                y: CW_USEDEFAULT,
+                width: CW_USEDEFAULT,
+                height: CW_USEDEFAULT,
+                parent: 0 as HWND,
+                menu: 0 as HMENU,
+                h_instance: 0 as HINSTANCE
+            }
+        }
+        /// Sets the window's title/name
+        pub fn name(mut self, name: LPCString) -> Self {
+            self.name = Some(name);
+            self
+        }
+        /// Sets the window style (the `dwStyle` argument of `CreateWindowExA`)
+        pub fn style(mut self, style: WindowStyle) -> Self {
+            self.style = style;
+            self
+        }
+        /// Sets the extended window style (the `dwExStyle` argument of `CreateWindowExA`)
+        pub fn ex_style(mut self, ex_style: DWORD) -> Self {
+            self.ex_style = Some(ex_style);
+            self
+        }
+        /// Sets the window's initial position, defaults to `CW_USEDEFAULT` for both axes
+        pub fn position(mut self, x: i32, y: i32) -> Self {
+            self.x = x;
+            self.y = y;
+            self
+        }
+        /// Sets the window's initial size, defaults to `CW_USEDEFAULT` for both dimensions
+        pub fn size(mut self, width: i32, height: i32) -> Self {
+            self.width = width;
+            self.height = height;
+            self
+        }
+        /// Sets the window's parent
+        pub fn parent(mut self, parent: &HWindow) -> Self {
+            self.parent = parent.get();
+            self
+        }
+        /// Sets the child-window identifier/menu handle (the `hMenu` argument of `CreateWindowExA`)
+        pub fn menu(mut self, menu: HMENU) -> Self {
+            self.menu = menu;
+            self
+        }
+        /// Sets the module instance the window is being created for
+        pub fn h_instance(mut self, h_instance: &HInstance) -> Self {
+            self.h_instance = h_instance.get();
+            self
+        }
+        /// Calls the Win32 function CreateWindowExA with the configured parameters, returning a
+        /// [Result] containing [HWindow] on success, or [ErrorCode] on a failure
+        #[doc(alias = "CreateWindowExA")]
+        pub fn build(self) -> Result<HWindow, ErrorCode> {
+            let class_name_cstr = self.class_name.as_cstr();
+            let window_name_cstr = match &self.name {
+                Some(name) => name.as_cstr(),
+                None => 0 as LPCSTR
+            };
+
+            let ret = unsafe {
+                CreateWindowExA(self.ex_style.unwrap_or(0), class_name_cstr, window_name_cstr, self.style.bits(),
+                    self.x, self.y, self.width, self.height, self.parent, self.menu, self.h_instance, 0 as LPVOID)
+            };
+
+            if ret == 0 as HWND {
+                Err(crate::get_last_error())
+            } else {
+                Ok(HWindow::new(ret))
+            }
+        }
+    }
 }
 
 
 use core::ffi::c_void;
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
 
 use types::*;
+use win_raw::{UINT, WPARAM, LPARAM, LRESULT};
+
+/// The `WM_QUIT` message identifier. [run_message_loop] returns `Ok(())` when it retrieves this
+/// message, and [peek_message] returns `None` for it, the same as for an empty queue.
+pub const WM_QUIT: UINT = win_raw::WM_QUIT;
+
+thread_local! {
+    // Holds the payload of a panic caught at the WndProc boundary, so it can be inspected once
+    // the message loop has unwound back out to safe Rust. See [wndproc_trampoline] and [take_panic_payload].
+    static CAUGHT_PANIC: RefCell<Option<Box<dyn Any + Send>>> = RefCell::new(None);
+}
+
+/// Takes the payload of the last panic caught while dispatching a message to a [WindowHandler], if any.
+///
+/// A caught panic also posts `WM_QUIT` to unwind the message loop, so this is meant to be checked
+/// once [run_message_loop] returns.
+pub fn take_panic_payload() -> Option<Box<dyn Any + Send>> {
+    CAUGHT_PANIC.with(|cell| cell.borrow_mut().take())
+}
+/// Calls `handler.handle()`, catching any panic so it cannot unwind across the `extern "system"`
+/// WndProc boundary into Win32. A caught panic is stashed for [take_panic_payload], `WM_QUIT` is
+/// posted so the owning message loop unwinds, and a safe default `LRESULT` of `0` is returned.
+fn call_handler(handler: &mut dyn WindowHandler, msg: UINT, w_param: WPARAM, l_param: LPARAM) -> Option<LRESULT> {
+    match catch_unwind(AssertUnwindSafe(|| handler.handle(msg, w_param, l_param))) {
+        Ok(ret) => ret,
+        Err(payload) => {
+            CAUGHT_PANIC.with(|cell| *cell.borrow_mut() = Some(payload));
+
+            unsafe {
+                win_raw::PostQuitMessage(0);
+            }
+
+            Some(0)
+        }
+    }
+}
 
 /// Calls the Win32 function GetLastError() and returns an [ErrorCode] containing the value from the Win32 call
 #[doc(alias = "GetLastError")]
@@ -129,7 +503,7 @@ pub fn register_class(class: &WinClass) -> Result<WinClassAtom, ErrorCode> {
     let win_class_internal = class.convert();
 
     unsafe {
-        ret = WinClassAtom::new(win_raw::RegisterClassExA(&win_class_internal));
+        ret = WinClassAtom::new(win_raw::RegisterClassExA(&win_class_internal), class.h_instance.get());
     }
 
     if ret.get() == 0 {
@@ -138,7 +512,7 @@ pub fn register_class(class: &WinClass) -> Result<WinClassAtom, ErrorCode> {
         Ok(ret)
     }
 }
-/// Calls the Win32 function GetModuleHandleA and returns a [Result] containing [HInstance] on success, 
+/// Calls the Win32 function GetModuleHandleA and returns a [Result] containing [HInstance] on success,
 /// or [ErrorCode] on a failure
 #[doc(alias = "GetModuleHandleA")]
 pub fn get_module_handle() -> Result<HInstance, ErrorCode> {
@@ -155,18 +529,54 @@ pub fn get_module_handle() -> Result<HInstance, ErrorCode> {
         Ok(HInstance::new(ret))
     }
 }
-/// Calls the Win32 function CreateWindowExA and returns a [Result] containing [HWindow] on success,
+/// Calls the Win32 function RegisterClassExW() and returns a [Result] containing [WinClassAtom] on success,
 /// or [ErrorCode] on a failure
+#[doc(alias = "RegisterClassExW")]
+pub fn register_class_w(class: &WinClassW) -> Result<WinClassAtom, ErrorCode> {
+    let ret: WinClassAtom;
+
+    let win_class_internal = class.convert();
+
+    unsafe {
+        ret = WinClassAtom::new(win_raw::RegisterClassExW(&win_class_internal), class.h_instance.get());
+    }
+
+    if ret.get() == 0 {
+        Err(get_last_error())
+    } else {
+        Ok(ret)
+    }
+}
+/// Calls the Win32 function GetModuleHandleW and returns a [Result] containing [HInstance] on success,
+/// or [ErrorCode] on a failure
+#[doc(alias = "GetModuleHandleW")]
+pub fn get_module_handle_w() -> Result<HInstance, ErrorCode> {
+    let ret;
+    let mod_name_cwstr = 0;
+
+    unsafe {
+        ret = win_raw::GetModuleHandleW(mod_name_cwstr as *const u16);
+    }
+
+    if ret as usize == 0 {
+        Err(get_last_error())
+    } else {
+        Ok(HInstance::new(ret))
+    }
+}
+/// Calls the Win32 function CreateWindowExA and returns a [Result] containing [HWindow] on success,
+/// or [ErrorCode] on a failure.
+///
+/// This always uses `WS_OVERLAPPEDWINDOW` at a fixed position/size; use [WindowBuilder] for control
+/// over the style, position and size.
 #[doc(alias = "CreateWindowExA")]
 pub fn create_window(class_name: &LPCString, window_name: &LPCString, h_instance: &HInstance) -> Result<HWindow, ErrorCode> {
-    // TODO: Implement full functionality for this function
     let ret;
     let class_name_cstr = class_name.as_cstr();
     let window_name_cstr = window_name.as_cstr();
 
     unsafe {
-        // TODO: The constants that configure a window style can be a vector of enums that just contain u32s
-        ret = win_raw::CreateWindowExA(0, class_name_cstr, window_name_cstr, 
+        ret = win_raw::CreateWindowExA(0, class_name_cstr, window_name_cstr,
         win_raw::WS_OVERLAPPEDWINDOW, 20, 20, 80, 80, 0 as *mut c_void, 0 as *mut c_void, h_instance.get(), 0 as *mut c_void);
     }
 
@@ -177,6 +587,25 @@ pub fn create_window(class_name: &LPCString, window_name: &LPCString, h_instance
         Ok(HWindow::new(ret))
     }
 }
+/// Calls the Win32 function CreateWindowExW and returns a [Result] containing [HWindow] on success,
+/// or [ErrorCode] on a failure. This is the wide counterpart of [create_window].
+#[doc(alias = "CreateWindowExW")]
+pub fn create_window_w(class_name: &WideString, window_name: &WideString, h_instance: &HInstance) -> Result<HWindow, ErrorCode> {
+    let ret;
+    let class_name_cwstr = class_name.as_cwstr();
+    let window_name_cwstr = window_name.as_cwstr();
+
+    unsafe {
+        ret = win_raw::CreateWindowExW(0, class_name_cwstr, window_name_cwstr,
+        win_raw::WS_OVERLAPPEDWINDOW, 20, 20, 80, 80, 0 as *mut c_void, 0 as *mut c_void, h_instance.get(), 0 as *mut c_void);
+    }
+
+    if ret == 0 as *mut c_void {
+        Err(get_last_error())
+    } else {
+        Ok(HWindow::new(ret))
+    }
+}
 /// Calls the Win32 function ShowWindow
 #[doc(alias = "ShowWindow")]
 pub fn show_window(h_window: &HWindow, cmd_show: i32) {
@@ -184,3 +613,129 @@ pub fn show_window(h_window: &HWindow, cmd_show: i32) {
         win_raw::ShowWindow(h_window.get(), cmd_show);
     }
 }
+/// The internal WndProc trampoline used by [create_window_with_handler].
+///
+/// On `WM_NCCREATE` it pulls the boxed [WindowHandler] out of the `CREATESTRUCTA` and stashes it in
+/// `GWLP_USERDATA`; on every later message it fetches the handler back out and calls it through
+/// [call_handler] (which guards against panics), falling back to `DefWindowProcA` when there is no
+/// handler yet or the handler returns `None`; on `WM_NCDESTROY` it reclaims and drops the box so the
+/// handler's lifetime matches the window's.
+extern "system" fn wndproc_trampoline(hwnd: *mut c_void, msg: u32, w_param: usize, l_param: isize) -> isize {
+    unsafe {
+        if msg == win_raw::WM_NCCREATE {
+            let create_struct = l_param as *const win_raw::CREATESTRUCTA;
+            let handler_ptr = (*create_struct).lpCreateParams as isize;
+
+            win_raw::SetWindowLongPtrA(hwnd, win_raw::GWLP_USERDATA, handler_ptr);
+
+            return win_raw::DefWindowProcA(hwnd, msg, w_param, l_param);
+        }
+
+        let handler_ptr = win_raw::GetWindowLongPtrA(hwnd, win_raw::GWLP_USERDATA) as *mut Box<dyn WindowHandler>;
+
+        if handler_ptr.is_null() {
+            return win_raw::DefWindowProcA(hwnd, msg, w_param, l_param);
+        }
+
+        if msg == win_raw::WM_NCDESTROY {
+            let mut handler = Box::from_raw(handler_ptr);
+            let ret = match call_handler(handler.as_mut().as_mut(), msg, w_param, l_param) {
+                Some(ret) => ret,
+                None => win_raw::DefWindowProcA(hwnd, msg, w_param, l_param)
+            };
+
+            win_raw::SetWindowLongPtrA(hwnd, win_raw::GWLP_USERDATA, 0);
+
+            return ret;
+        }
+
+        let handler = &mut *handler_ptr;
+
+        match call_handler(handler.as_mut(), msg, w_param, l_param) {
+            Some(ret) => ret,
+            None => win_raw::DefWindowProcA(hwnd, msg, w_param, l_param)
+        }
+    }
+}
+/// Returns a [WinProc] pointing at the crate's internal WndProc trampoline. Use this as a class's
+/// `win_proc` to enable dispatch to a [WindowHandler] attached via [create_window_with_handler].
+pub fn handler_wndproc() -> WinProc {
+    WinProc::new(Some(wndproc_trampoline))
+}
+/// Calls the Win32 function CreateWindowExA, attaching `handler` to the new window so it receives
+/// the window's messages instead of a raw WndProc. The window's class must use [handler_wndproc]
+/// as its `win_proc` for this to take effect.
+#[doc(alias = "CreateWindowExA")]
+pub fn create_window_with_handler(class_name: &LPCString, window_name: &LPCString, h_instance: &HInstance, handler: Box<dyn WindowHandler>) -> Result<HWindow, ErrorCode> {
+    let ret;
+    let class_name_cstr = class_name.as_cstr();
+    let window_name_cstr = window_name.as_cstr();
+    let handler_ptr = Box::into_raw(Box::new(handler)) as *mut c_void;
+
+    unsafe {
+        ret = win_raw::CreateWindowExA(0, class_name_cstr, window_name_cstr,
+        win_raw::WS_OVERLAPPEDWINDOW, 20, 20, 80, 80, 0 as *mut c_void, 0 as *mut c_void, h_instance.get(), handler_ptr);
+    }
+
+    if ret == 0 as *mut c_void {
+        unsafe {
+            drop(Box::from_raw(handler_ptr as *mut Box<dyn WindowHandler>));
+        }
+        Err(get_last_error())
+    } else {
+        Ok(HWindow::new(ret))
+    }
+}
+/// Runs a blocking message loop by repeatedly calling GetMessageA, TranslateMessage and DispatchMessageA.
+///
+/// Returns `Ok(())` once `GetMessageA` reports a `WM_QUIT` message, or `Err(ErrorCode)` if `GetMessageA`
+/// itself fails.
+#[doc(alias = "GetMessageA")]
+pub fn run_message_loop() -> Result<(), ErrorCode> {
+    loop {
+        let mut msg: win_raw::MSG = unsafe { std::mem::zeroed() };
+
+        let ret = unsafe { win_raw::GetMessageA(&mut msg, 0 as *mut c_void, 0, 0) };
+
+        if ret == 0 {
+            return Ok(());
+        } else if ret == -1 {
+            return Err(get_last_error());
+        }
+
+        unsafe {
+            win_raw::TranslateMessage(&msg);
+            win_raw::DispatchMessageA(&msg);
+        }
+    }
+}
+/// Calls the Win32 function PeekMessageA with `PM_REMOVE` and, if a message was waiting, translates and
+/// dispatches it before returning it to the caller.
+///
+/// Returns `Some(Message)` if a message was pumped, or `None` if the queue was empty **or** the message
+/// was `WM_QUIT`. Unlike `GetMessageA`, `PeekMessageA`'s nonzero return only means "a message was
+/// retrieved", not "it wasn't `WM_QUIT`", so this checks `msg.message` against [WM_QUIT] itself before
+/// dispatching; this never blocks, so it can be interleaved with other per-frame work.
+#[doc(alias = "PeekMessageA")]
+pub fn peek_message() -> Option<Message> {
+    let mut msg: win_raw::MSG = unsafe { std::mem::zeroed() };
+
+    let has_message = unsafe {
+        win_raw::PeekMessageA(&mut msg, 0 as *mut c_void, 0, 0, win_raw::PM_REMOVE)
+    };
+
+    if has_message == 0 {
+        return None;
+    }
+
+    if msg.message == WM_QUIT {
+        return None;
+    }
+
+    unsafe {
+        win_raw::TranslateMessage(&msg);
+        win_raw::DispatchMessageA(&msg);
+    }
+
+    Some(Message::new(msg))
+}
@@ -37,11 +37,37 @@ pub const WS_TABSTOP: u32 = 0x00010000;
 pub const WS_VISIBLE: u32 = 0x10000000;
 pub const WS_VSCROLL: u32 = 0x00200000;
 
+pub const PM_NOREMOVE: u32 = 0x0000;
+pub const PM_REMOVE: u32 = 0x0001;
+
+pub const WM_QUIT: u32 = 0x0012;
+pub const WM_NCCREATE: u32 = 0x0081;
+pub const WM_NCDESTROY: u32 = 0x0082;
+
+pub const GWLP_USERDATA: i32 = -21;
+
+pub const CW_USEDEFAULT: i32 = 0x80000000u32 as i32;
+
+pub const FORMAT_MESSAGE_ALLOCATE_BUFFER: u32 = 0x00000100;
+pub const FORMAT_MESSAGE_FROM_SYSTEM: u32 = 0x00001000;
+pub const FORMAT_MESSAGE_IGNORE_INSERTS: u32 = 0x00000200;
+
+pub const LANG_NEUTRAL: u32 = 0x00;
+pub const SUBLANG_DEFAULT: u32 = 0x01;
+
+/// Combines a primary and sub language id the way the Win32 `MAKELANGID` macro does
+pub const fn make_lang_id(primary: u32, sub: u32) -> u32 {
+    (sub << 10) | primary
+}
+
 pub type VOID = *mut c_void;
 pub type PVOID = *mut c_void;
 pub type LPVOID = *mut c_void;
 
-pub type BOOL = bool;
+// BOOL is a 4-byte C int whose true value is only documented as "nonzero" (not necessarily 1), so it's
+// aliased to i32 rather than Rust's 1-byte bool: reading a BOOL return as a bool is UB for any bit
+// pattern other than 0x00/0x01, independent of whether the result is branched on.
+pub type BOOL = i32;
 
 pub type WORD = u16;
 pub type ATOM = WORD;
@@ -58,6 +84,9 @@ pub type LPARAM = LONG_PTR;
 pub type LRESULT = LONG_PTR;
 
 pub type LPCSTR = *const u8;
+pub type LPCWSTR = *const u16;
+pub type LPSTR = *mut u8;
+pub type LPWSTR = *mut u16;
 
 pub type HANDLE = PVOID;
 pub type HWND = HANDLE;
@@ -67,6 +96,7 @@ pub type HMODULE = HINSTANCE;
 pub type HICON = HANDLE;
 pub type HCURSOR = HICON;
 pub type HBRUSH = HANDLE;
+pub type HLOCAL = HANDLE;
 
 pub type WNDPROC = Option<extern "system" fn(HWND, UINT, WPARAM, LPARAM) -> LRESULT>;
 
@@ -104,19 +134,106 @@ impl WNDCLASSEXA { // TODO: Implement full functionality for this structure
     }
 }
 
+#[repr(C)]
+pub struct WNDCLASSEXW {
+    cbSize: UINT,
+    style: UINT,
+    lpfnWndProc: WNDPROC,
+    cbClsExtra: i32,
+    cbWndExtra: i32,
+    hInstance: HINSTANCE,
+    hIcon: HICON,
+    hCursor: HCURSOR,
+    hbrBackground: HBRUSH,
+    lpszMenuName: LPCWSTR,
+    lpszClassName: LPCWSTR,
+    hIconSm: HICON
+}
+impl WNDCLASSEXW { // TODO: Implement full functionality for this structure
+    pub fn new(wrapper: &crate::types::WinClassW) -> Self {
+        WNDCLASSEXW {
+            cbSize: mem::size_of::<WNDCLASSEXW>() as UINT,
+            style: wrapper.style,
+            lpfnWndProc: wrapper.win_proc.get(),
+            cbClsExtra: wrapper.cls_extra,
+            cbWndExtra: wrapper.win_extra,
+            hInstance: wrapper.h_instance.get(),
+            hIcon: wrapper.h_icon.get(),
+            hCursor: wrapper.h_cursor.get(),
+            hbrBackground: wrapper.h_br_background.get(),
+            lpszMenuName: wrapper.menu_name.as_cwstr(),
+            lpszClassName: wrapper.class_name.as_cwstr(),
+            hIconSm: wrapper.h_icon_small.get()
+        }
+    }
+}
+
+#[repr(C)]
+pub struct POINT {
+    pub x: i32,
+    pub y: i32
+}
+
+#[repr(C)]
+pub struct CREATESTRUCTA {
+    pub lpCreateParams: LPVOID,
+    pub hInstance: HINSTANCE,
+    pub hMenu: HMENU,
+    pub hwndParent: HWND,
+    pub cy: i32,
+    pub cx: i32,
+    pub y: i32,
+    pub x: i32,
+    pub style: i32,
+    pub lpszName: LPCSTR,
+    pub lpszClass: LPCSTR,
+    pub dwExStyle: DWORD
+}
+
+#[repr(C)]
+pub struct MSG {
+    pub hwnd: HWND,
+    pub message: UINT,
+    pub wParam: WPARAM,
+    pub lParam: LPARAM,
+    pub time: DWORD,
+    pub pt: POINT
+}
+
 #[link(name="Kernel32")]
 extern "system" {
     pub fn GetLastError() -> DWORD;
     pub fn GetModuleHandleA(lpModuleName: LPCSTR) -> HMODULE;
+    pub fn GetModuleHandleW(lpModuleName: LPCWSTR) -> HMODULE;
+    pub fn FormatMessageA(dwFlags: DWORD, lpSource: LPVOID, dwMessageId: DWORD, dwLanguageId: DWORD,
+        lpBuffer: *mut LPSTR, nSize: DWORD, Arguments: LPVOID) -> DWORD;
+    pub fn FormatMessageW(dwFlags: DWORD, lpSource: LPVOID, dwMessageId: DWORD, dwLanguageId: DWORD,
+        lpBuffer: *mut LPWSTR, nSize: DWORD, Arguments: LPVOID) -> DWORD;
+    pub fn LocalFree(hMem: HLOCAL) -> HLOCAL;
 }
 
 #[link(name="User32")]
 extern "system" {
     pub fn ShowWindow(hWnd: HWND, nCmdShow: i32) -> BOOL;
     pub fn DefWindowProcA(hWnd: HWND, Msg: UINT, wParam: WPARAM, lParam: LPARAM) -> LRESULT;
+    pub fn DefWindowProcW(hWnd: HWND, Msg: UINT, wParam: WPARAM, lParam: LPARAM) -> LRESULT;
     pub fn RegisterClassExA(lpWndClass: *const WNDCLASSEXA) -> ATOM;
-    pub fn CreateWindowExA(dwExStyle: DWORD, lpClassName: LPCSTR, lpWindowName: LPCSTR, dwStyle: DWORD, x: i32, y: i32, 
+    pub fn RegisterClassExW(lpWndClass: *const WNDCLASSEXW) -> ATOM;
+    pub fn CreateWindowExA(dwExStyle: DWORD, lpClassName: LPCSTR, lpWindowName: LPCSTR, dwStyle: DWORD, x: i32, y: i32,
+        nWidth: i32, nHeight: i32, hWndParent: HWND, hMenu: HMENU, hInstance: HINSTANCE, lpParam: LPVOID) -> HWND;
+    pub fn CreateWindowExW(dwExStyle: DWORD, lpClassName: LPCWSTR, lpWindowName: LPCWSTR, dwStyle: DWORD, x: i32, y: i32,
         nWidth: i32, nHeight: i32, hWndParent: HWND, hMenu: HMENU, hInstance: HINSTANCE, lpParam: LPVOID) -> HWND;
+    // GetMessageA returns a tri-state int (-1 on failure, 0 on WM_QUIT, nonzero otherwise) rather than
+    // a plain nonzero/zero BOOL, so its return type is left as the raw i32 to keep that distinction explicit.
+    pub fn GetMessageA(lpMsg: *mut MSG, hWnd: HWND, wMsgFilterMin: UINT, wMsgFilterMax: UINT) -> i32;
+    pub fn PeekMessageA(lpMsg: *mut MSG, hWnd: HWND, wMsgFilterMin: UINT, wMsgFilterMax: UINT, wRemoveMsg: UINT) -> BOOL;
+    pub fn TranslateMessage(lpMsg: *const MSG) -> BOOL;
+    pub fn DispatchMessageA(lpMsg: *const MSG) -> LRESULT;
+    pub fn GetWindowLongPtrA(hWnd: HWND, nIndex: i32) -> LONG_PTR;
+    pub fn SetWindowLongPtrA(hWnd: HWND, nIndex: i32, dwNewLong: LONG_PTR) -> LONG_PTR;
+    pub fn PostQuitMessage(nExitCode: i32);
+    pub fn DestroyWindow(hWnd: HWND) -> BOOL;
+    pub fn UnregisterClassA(lpClassName: LPCSTR, hInstance: HINSTANCE) -> BOOL;
 }
 
 // The following function definition is kept for reference